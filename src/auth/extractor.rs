@@ -0,0 +1,88 @@
+use crate::auth::jwt;
+use crate::errors::ApiError;
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use std::future::{ready, Ready};
+use uuid::Uuid;
+
+/// Extractor for the `Authorization: Bearer <token>` header. Add it as a handler
+/// parameter on any route that must reject unauthenticated requests.
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+    pub user_id: Uuid,
+    pub username: String,
+}
+
+impl FromRequest for AuthenticatedUser {
+    type Error = ApiError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let token = req
+            .headers()
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+
+        let result = match token {
+            Some(token) => jwt::verify_token(token).map(|claims| AuthenticatedUser {
+                user_id: claims.sub,
+                username: claims.username,
+            }),
+            None => Err(ApiError::Unauthorized),
+        };
+
+        ready(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{issue_token, User};
+    use actix_web::test::TestRequest;
+    use actix_web::FromRequest;
+
+    #[actix_web::test]
+    async fn extracts_the_user_from_a_valid_bearer_token() {
+        let user = User {
+            id: Uuid::new_v4(),
+            username: "alice".to_string(),
+            password_hash: "unused".to_string(),
+        };
+        let token = issue_token(&user).unwrap();
+        let req = TestRequest::default()
+            .insert_header(("Authorization", format!("Bearer {}", token)))
+            .to_http_request();
+
+        let authenticated = AuthenticatedUser::from_request(&req, &mut actix_web::dev::Payload::None)
+            .await
+            .unwrap();
+
+        assert_eq!(authenticated.user_id, user.id);
+        assert_eq!(authenticated.username, user.username);
+    }
+
+    #[actix_web::test]
+    async fn rejects_a_missing_authorization_header() {
+        let req = TestRequest::default().to_http_request();
+
+        let err = AuthenticatedUser::from_request(&req, &mut actix_web::dev::Payload::None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Unauthorized));
+    }
+
+    #[actix_web::test]
+    async fn rejects_a_garbled_bearer_token() {
+        let req = TestRequest::default()
+            .insert_header(("Authorization", "Bearer not-a-real-token"))
+            .to_http_request();
+
+        let err = AuthenticatedUser::from_request(&req, &mut actix_web::dev::Payload::None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Unauthorized));
+    }
+}
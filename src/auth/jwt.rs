@@ -0,0 +1,100 @@
+use crate::auth::User;
+use crate::errors::{ApiError, ApiResult};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::sync::Once;
+use uuid::Uuid;
+
+const TOKEN_TTL_HOURS: i64 = 24;
+
+static WARN_DEFAULT_SECRET: Once = Once::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: Uuid,
+    pub username: String,
+    pub exp: i64,
+}
+
+fn secret() -> String {
+    std::env::var("JWT_SECRET").unwrap_or_else(|_| {
+        WARN_DEFAULT_SECRET.call_once(|| {
+            eprintln!(
+                "WARNING: JWT_SECRET is not set; signing tokens with a hardcoded default secret. \
+                 Anyone who reads this source can forge tokens. Set JWT_SECRET before deploying."
+            );
+        });
+        "dev-secret-change-me".to_string()
+    })
+}
+
+pub fn issue_token(user: &User) -> ApiResult<String> {
+    let claims = Claims {
+        sub: user.id,
+        username: user.username.clone(),
+        exp: (Utc::now() + Duration::hours(TOKEN_TTL_HOURS)).timestamp(),
+    };
+
+    encode(&Header::default(), &claims, &EncodingKey::from_secret(secret().as_bytes()))
+        .map_err(|_| ApiError::InternalError)
+}
+
+pub fn verify_token(token: &str) -> ApiResult<Claims> {
+    decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret().as_bytes()),
+        &Validation::default(),
+    )
+    .map(|data| data.claims)
+    .map_err(|_| ApiError::Unauthorized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_user() -> User {
+        User {
+            id: Uuid::new_v4(),
+            username: "alice".to_string(),
+            password_hash: "unused".to_string(),
+        }
+    }
+
+    #[test]
+    fn issue_then_verify_round_trips_the_user() {
+        let user = test_user();
+        let token = issue_token(&user).unwrap();
+
+        let claims = verify_token(&token).unwrap();
+        assert_eq!(claims.sub, user.id);
+        assert_eq!(claims.username, user.username);
+    }
+
+    #[test]
+    fn verify_token_rejects_garbage() {
+        assert!(matches!(verify_token("not-a-jwt"), Err(ApiError::Unauthorized)));
+    }
+
+    #[test]
+    fn verify_token_rejects_a_tampered_signature() {
+        let token = issue_token(&test_user()).unwrap();
+        let mut tampered = token.clone();
+        tampered.push('x');
+
+        assert!(matches!(verify_token(&tampered), Err(ApiError::Unauthorized)));
+    }
+
+    #[test]
+    fn verify_token_rejects_an_expired_token() {
+        let claims = Claims {
+            sub: Uuid::new_v4(),
+            username: "alice".to_string(),
+            exp: (Utc::now() - Duration::hours(1)).timestamp(),
+        };
+        let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(secret().as_bytes())).unwrap();
+
+        assert!(matches!(verify_token(&token), Err(ApiError::Unauthorized)));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::auth::User;
+use crate::errors::{ApiError, ApiResult};
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Argon2-hashed password store, persisted as JSON alongside the blog data.
+/// Seeds a default `admin`/`admin` account on first run so the API is usable
+/// out of the box; operators are expected to change it immediately.
+#[derive(Clone)]
+pub struct AuthStore {
+    users: Arc<Mutex<HashMap<String, User>>>,
+    file_path: PathBuf,
+}
+
+impl AuthStore {
+    pub fn new(file_path: PathBuf) -> ApiResult<Self> {
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| ApiError::StorageError(format!("Failed to create data directory: {}", e)))?;
+        }
+
+        let users = if file_path.exists() {
+            let contents = fs::read_to_string(&file_path)
+                .map_err(|e| ApiError::StorageError(format!("Failed to read file: {}", e)))?;
+            serde_json::from_str(&contents)
+                .map_err(|e| ApiError::StorageError(format!("Failed to parse JSON: {}", e)))?
+        } else {
+            eprintln!(
+                "WARNING: no user store found at {:?}; seeding a default admin/admin account. \
+                 Change this password immediately -- anyone who knows the default can log in.",
+                file_path
+            );
+            let mut users = HashMap::new();
+            let admin = User {
+                id: Uuid::new_v4(),
+                username: "admin".to_string(),
+                password_hash: Self::hash_password("admin")?,
+            };
+            users.insert(admin.username.clone(), admin);
+            users
+        };
+
+        let store = AuthStore {
+            users: Arc::new(Mutex::new(users)),
+            file_path,
+        };
+
+        if !store.file_path.exists() {
+            store.save()?;
+        }
+
+        Ok(store)
+    }
+
+    fn save(&self) -> ApiResult<()> {
+        let users = self.users.lock().unwrap();
+        let json = serde_json::to_string_pretty(&*users)
+            .map_err(|e| ApiError::StorageError(format!("Failed to serialize: {}", e)))?;
+
+        let mut file = File::create(&self.file_path)
+            .map_err(|e| ApiError::StorageError(format!("Failed to create file: {}", e)))?;
+
+        file.write_all(json.as_bytes())
+            .map_err(|e| ApiError::StorageError(format!("Failed to write file: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn hash_password(password: &str) -> ApiResult<String> {
+        let salt = SaltString::generate(&mut OsRng);
+        Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|_| ApiError::InternalError)
+    }
+
+    pub fn verify_password(&self, username: &str, password: &str) -> ApiResult<User> {
+        let users = self.users.lock().unwrap();
+        let user = users.get(username).ok_or(ApiError::Unauthorized)?;
+
+        let parsed_hash = PasswordHash::new(&user.password_hash).map_err(|_| ApiError::InternalError)?;
+        Argon2::default()
+            .verify_password(password.as_bytes(), &parsed_hash)
+            .map_err(|_| ApiError::Unauthorized)?;
+
+        Ok(user.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("blocking_backend-test-users-{}-{}.json", name, Uuid::new_v4()))
+    }
+
+    #[test]
+    fn new_seeds_a_default_admin_account() {
+        let path = temp_store_path("seed");
+        let store = AuthStore::new(path.clone()).unwrap();
+
+        assert!(store.verify_password("admin", "admin").is_ok());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn verify_password_rejects_a_wrong_password() {
+        let path = temp_store_path("wrong-password");
+        let store = AuthStore::new(path.clone()).unwrap();
+
+        let err = store.verify_password("admin", "not-the-password").unwrap_err();
+        assert!(matches!(err, ApiError::Unauthorized));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn verify_password_rejects_an_unknown_username() {
+        let path = temp_store_path("unknown-user");
+        let store = AuthStore::new(path.clone()).unwrap();
+
+        let err = store.verify_password("nobody", "admin").unwrap_err();
+        assert!(matches!(err, ApiError::Unauthorized));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn hash_password_salts_each_call_differently() {
+        let first = AuthStore::hash_password("admin").unwrap();
+        let second = AuthStore::hash_password("admin").unwrap();
+        assert_ne!(first, second);
+    }
+}
@@ -1,5 +1,15 @@
 use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use serde::Serialize;
 use thiserror::Error;
+use utoipa::ToSchema;
+
+/// Body returned for every `ApiError` response. Requests rejected by actix's
+/// own extractors (malformed JSON, form or path values) get actix's default
+/// error body instead.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    pub error: String,
+}
 
 #[derive(Debug, Error)]
 pub enum ApiError {
@@ -9,12 +19,18 @@ pub enum ApiError {
     #[error("Comment not found")]
     CommentNotFound,
 
+    #[error("Media not found")]
+    MediaNotFound,
+
     #[error("Validation error: {0}")]
     ValidationError(String),
 
     #[error("Storage error: {0}")]
     StorageError(String),
 
+    #[error("Unauthorized")]
+    Unauthorized,
+
     #[error("Internal server error")]
     InternalError,
 }
@@ -22,16 +38,17 @@ pub enum ApiError {
 impl ResponseError for ApiError {
     fn status_code(&self) -> StatusCode {
         match self {
-            ApiError::PostNotFound | ApiError::CommentNotFound => StatusCode::NOT_FOUND,
+            ApiError::PostNotFound | ApiError::CommentNotFound | ApiError::MediaNotFound => StatusCode::NOT_FOUND,
             ApiError::ValidationError(_) => StatusCode::BAD_REQUEST,
+            ApiError::Unauthorized => StatusCode::UNAUTHORIZED,
             ApiError::StorageError(_) | ApiError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::build(self.status_code()).json(serde_json::json!({ 
-            "error": self.to_string()
-        }))
+        HttpResponse::build(self.status_code()).json(ErrorResponse {
+            error: self.to_string(),
+        })
     }
 }
 
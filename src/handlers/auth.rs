@@ -0,0 +1,36 @@
+use crate::auth::{issue_token, AuthStore, AuthenticatedUser, LoginRequest, LoginResponse, MeResponse};
+use crate::errors::ApiResult;
+use actix_web::{get, post, web, HttpResponse};
+
+#[utoipa::path(
+    post,
+    path = "/login",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "Signed JWT for the authenticated user", body = LoginResponse),
+        (status = 401, description = "Bad username or password", body = crate::errors::ErrorResponse),
+    ),
+)]
+#[post("/login")]
+pub async fn login(auth_store: web::Data<AuthStore>, credentials: web::Json<LoginRequest>) -> ApiResult<HttpResponse> {
+    let user = auth_store.verify_password(&credentials.username, &credentials.password)?;
+    let token = issue_token(&user)?;
+    Ok(HttpResponse::Ok().json(LoginResponse { token }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/me",
+    responses(
+        (status = 200, description = "The currently authenticated user", body = MeResponse),
+        (status = 401, description = "Missing or invalid bearer token", body = crate::errors::ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[get("/me")]
+pub async fn me(user: AuthenticatedUser) -> ApiResult<HttpResponse> {
+    Ok(HttpResponse::Ok().json(MeResponse {
+        id: user.user_id,
+        username: user.username,
+    }))
+}
@@ -1,37 +1,79 @@
+use crate::auth::AuthenticatedUser;
 use crate::errors::{ApiError, ApiResult};
 use crate::models::{Comment, CreateComment};
-use crate::storage::Storage;
+use crate::storage::BlogStore;
 use actix_web::{delete, get, post, web, HttpResponse};
+use std::sync::Arc;
 use uuid::Uuid;
 
+#[utoipa::path(
+    get,
+    path = "/posts/{post_id}/comments",
+    params(("post_id" = Uuid, Path, description = "Post id")),
+    responses(
+        (status = 200, description = "Comments on the post", body = Vec<Comment>),
+        (status = 404, description = "No post with that id", body = crate::errors::ErrorResponse),
+    ),
+)]
 #[get("/posts/{post_id}/comments")]
-pub async fn get_comments(storage: web::Data<Storage>, post_id: web::Path<Uuid>) -> ApiResult<HttpResponse> {
-    let comments = storage.get_post_comments(*post_id)?;
+pub async fn get_comments(store: web::Data<Arc<dyn BlogStore>>, post_id: web::Path<Uuid>) -> ApiResult<HttpResponse> {
+    let comments = store.get_post_comments(*post_id).await?;
     Ok(HttpResponse::Ok().json(comments))
 }
 
+#[utoipa::path(
+    post,
+    path = "/posts/{post_id}/comments",
+    params(("post_id" = Uuid, Path, description = "Post id")),
+    request_body = CreateComment,
+    responses(
+        (status = 201, description = "Comment created", body = Comment),
+        (status = 400, description = "Validation error", body = crate::errors::ErrorResponse),
+        (status = 401, description = "Missing or invalid bearer token", body = crate::errors::ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
 #[post("/posts/{post_id}/comments")]
-pub async fn create_comment(storage: web::Data<Storage>, post_id: web::Path<Uuid>, new_comment: web::Json<CreateComment>) -> ApiResult<HttpResponse> {
+pub async fn create_comment(
+    store: web::Data<Arc<dyn BlogStore>>,
+    user: AuthenticatedUser,
+    post_id: web::Path<Uuid>,
+    new_comment: web::Json<CreateComment>,
+) -> ApiResult<HttpResponse> {
     // Validation
-    if new_comment.author.trim().is_empty() {
-        return Err(ApiError::ValidationError("Author cannot be empty".to_string()));
-    }
     if new_comment.content.trim().is_empty() {
         return Err(ApiError::ValidationError("Content cannot be empty".to_string()));
     }
 
-    let comment = Comment::new(CreateComment {
-        post_id: *post_id,
-        author: new_comment.author.clone(),
-        content: new_comment.content.clone(),
-    });
+    let comment = Comment::new(
+        CreateComment {
+            post_id: *post_id,
+            content: new_comment.content.clone(),
+        },
+        user.username,
+    );
 
-    let created_comment = storage.create_comment(comment)?;
+    let created_comment = store.create_comment(comment).await?;
     Ok(HttpResponse::Created().json(created_comment))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/comments/{id}",
+    params(("id" = Uuid, Path, description = "Comment id")),
+    responses(
+        (status = 204, description = "Comment deleted"),
+        (status = 401, description = "Missing or invalid bearer token", body = crate::errors::ErrorResponse),
+        (status = 404, description = "No comment with that id", body = crate::errors::ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
 #[delete("/comments/{id}")]
-pub async fn delete_comment(storage: web::Data<Storage>, id: web::Path<Uuid>) -> ApiResult<HttpResponse> {
-    storage.delete_comment(*id)?;
+pub async fn delete_comment(
+    store: web::Data<Arc<dyn BlogStore>>,
+    _user: AuthenticatedUser,
+    id: web::Path<Uuid>,
+) -> ApiResult<HttpResponse> {
+    store.delete_comment(*id).await?;
     Ok(HttpResponse::NoContent().finish())
-}
\ No newline at end of file
+}
@@ -0,0 +1,80 @@
+use crate::auth::AuthenticatedUser;
+use crate::errors::ApiError;
+use crate::errors::ApiResult;
+use crate::media::{MediaStore, MediaUploadResponse};
+use actix_multipart::Multipart;
+use actix_web::{get, post, web, HttpResponse};
+use futures_util::{StreamExt, TryStreamExt};
+use std::sync::Arc;
+
+#[utoipa::path(
+    post,
+    path = "/media",
+    request_body(content = Vec<u8>, description = "Multipart body with a single file field", content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "File stored", body = MediaUploadResponse),
+        (status = 400, description = "Too large, wrong MIME type, or malformed body", body = crate::errors::ErrorResponse),
+        (status = 401, description = "Missing or invalid bearer token", body = crate::errors::ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
+#[post("/media")]
+pub async fn upload_media(
+    media_store: web::Data<Arc<dyn MediaStore>>,
+    _user: AuthenticatedUser,
+    mut payload: Multipart,
+) -> ApiResult<HttpResponse> {
+    let field = payload
+        .try_next()
+        .await
+        .map_err(|e| ApiError::ValidationError(format!("Invalid multipart body: {}", e)))?
+        .ok_or_else(|| ApiError::ValidationError("No file field in upload".to_string()))?;
+
+    let stream = field
+        .map(|chunk| chunk.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)))
+        .boxed();
+
+    let id = media_store.write(stream).await?;
+    Ok(HttpResponse::Created().json(MediaUploadResponse {
+        url: format!("/media/{}", id),
+        id,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/media/{id}",
+    params(("id" = String, Path, description = "Content-addressed media id returned by POST /media")),
+    responses(
+        (status = 200, description = "The stored file, streamed back with its sniffed Content-Type"),
+        (status = 404, description = "No media with that id", body = crate::errors::ErrorResponse),
+    ),
+)]
+#[get("/media/{id}")]
+pub async fn get_media(
+    media_store: web::Data<Arc<dyn MediaStore>>,
+    id: web::Path<String>,
+) -> ApiResult<HttpResponse> {
+    let (content_type, stream) = media_store.read(&id).await?;
+    let stream = stream.map_err(actix_web::error::ErrorInternalServerError);
+    Ok(HttpResponse::Ok().content_type(content_type).streaming(stream))
+}
+
+#[utoipa::path(
+    get,
+    path = "/media/{id}/thumbnail",
+    params(("id" = String, Path, description = "Content-addressed media id returned by POST /media")),
+    responses(
+        (status = 200, description = "A downscaled JPEG thumbnail of the stored image"),
+        (status = 404, description = "No media with that id, it isn't an image, or its thumbnail hasn't finished generating yet", body = crate::errors::ErrorResponse),
+    ),
+)]
+#[get("/media/{id}/thumbnail")]
+pub async fn get_media_thumbnail(
+    media_store: web::Data<Arc<dyn MediaStore>>,
+    id: web::Path<String>,
+) -> ApiResult<HttpResponse> {
+    let stream = media_store.read_thumbnail(&id).await?;
+    let stream = stream.map_err(actix_web::error::ErrorInternalServerError);
+    Ok(HttpResponse::Ok().content_type("image/jpeg").streaming(stream))
+}
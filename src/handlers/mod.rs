@@ -0,0 +1,5 @@
+pub mod auth;
+pub mod comments;
+pub mod media;
+pub mod posts;
+pub mod webmentions;
@@ -1,49 +1,95 @@
+use crate::auth::AuthenticatedUser;
 use crate::errors::{ApiError, ApiResult};
-use crate::models::{CreatePost, Post, UpdatePost};
-use crate::storage::Storage; 
+use crate::models::{CreatePost, Post, PostWithComments, UpdatePost};
+use crate::storage::BlogStore;
 use actix_web::{delete, get, post, put, web, HttpResponse};
+use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
+#[utoipa::path(
+    get,
+    path = "/posts",
+    responses((status = 200, description = "All posts, keyed by id", body = HashMap<Uuid, Post>)),
+)]
 #[get("/posts")]
-pub async fn get_posts(storage: web::Data<Storage>) -> ApiResult<HttpResponse> {
-    let posts = storage.get_all_posts()?;
+pub async fn get_posts(store: web::Data<Arc<dyn BlogStore>>) -> ApiResult<HttpResponse> {
+    let posts = store.get_all_posts().await?;
     Ok(HttpResponse::Ok().json(posts))
 }
 
+#[utoipa::path(
+    get,
+    path = "/posts/{id}",
+    params(("id" = String, Path, description = "Post id (Uuid) or slug, current or retained alias")),
+    responses(
+        (status = 200, description = "The post and its comments", body = PostWithComments),
+        (status = 404, description = "No post matches that id or slug", body = crate::errors::ErrorResponse),
+    ),
+)]
 #[get("/posts/{id}")]
-pub async fn get_post(storage: web::Data<Storage>, id: web::Path<Uuid>) -> ApiResult<HttpResponse> {
-    let post = storage.get_post(*id)?;
-    let comments = storage.get_post_comments(*id)?;
+pub async fn get_post(store: web::Data<Arc<dyn BlogStore>>, id: web::Path<String>) -> ApiResult<HttpResponse> {
+    // `id` may be a post's Uuid or one of its slugs (current or alias).
+    let post_id = store.resolve_post_id(&id).await?;
+    let post = store.get_post(post_id).await?;
+    let comments = store.get_post_comments(post_id).await?;
 
-    Ok(HttpResponse::Ok().json(serde_json::json!({
-        "post": post,
-        "comments": comments
-    })))
+    Ok(HttpResponse::Ok().json(PostWithComments { post, comments }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/posts",
+    request_body = CreatePost,
+    responses(
+        (status = 201, description = "Post created", body = Post),
+        (status = 400, description = "Validation error", body = crate::errors::ErrorResponse),
+        (status = 401, description = "Missing or invalid bearer token", body = crate::errors::ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
 #[post("/posts")]
-pub async fn create_post(storage: web::Data<Storage>, new_post: web::Json<CreatePost>) -> ApiResult<HttpResponse> {
-    // Validation 
+pub async fn create_post(
+    store: web::Data<Arc<dyn BlogStore>>,
+    user: AuthenticatedUser,
+    new_post: web::Json<CreatePost>,
+) -> ApiResult<HttpResponse> {
+    // Validation
     if new_post.title.trim().is_empty() {
         return Err(ApiError::ValidationError("Title cannot be empty".to_string()));
     }
     if new_post.content.trim().is_empty() {
         return Err(ApiError::ValidationError("Content cannot be empty".to_string()));
     }
-    if new_post.author.trim().is_empty() {
-        return Err(ApiError::ValidationError("Author cannot be empty".to_string()));
-    }
     if new_post.title.len() > 200 {
         return Err(ApiError::ValidationError("Title too long (max 200 characters)".to_string()));
     }
 
-    let post = Post::new(new_post.into_inner());
-    let created_post = storage.create_post(post)?;
+    let post = Post::new(new_post.into_inner(), user.username);
+    let created_post = store.create_post(post).await?;
     Ok(HttpResponse::Created().json(created_post))
 }
 
+#[utoipa::path(
+    put,
+    path = "/posts/{id}",
+    params(("id" = Uuid, Path, description = "Post id")),
+    request_body = UpdatePost,
+    responses(
+        (status = 200, description = "Post updated", body = Post),
+        (status = 400, description = "Validation error", body = crate::errors::ErrorResponse),
+        (status = 401, description = "Missing or invalid bearer token", body = crate::errors::ErrorResponse),
+        (status = 404, description = "No post with that id", body = crate::errors::ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
 #[put("/posts/{id}")]
-pub async fn update_post(storage: web::Data<Storage>, id: web::Path<Uuid>, update_post: web::Json<UpdatePost>) -> ApiResult<HttpResponse> {
+pub async fn update_post(
+    store: web::Data<Arc<dyn BlogStore>>,
+    _user: AuthenticatedUser,
+    id: web::Path<Uuid>,
+    update_post: web::Json<UpdatePost>,
+) -> ApiResult<HttpResponse> {
     // Validation
     if let Some(title) = update_post.title.as_ref() {
         if title.trim().is_empty() {
@@ -59,12 +105,29 @@ pub async fn update_post(storage: web::Data<Storage>, id: web::Path<Uuid>, updat
         }
     }
 
-    let updated_post = storage.update_post(*id, update_post.title.clone(), update_post.content.clone())?;
+    let updated_post = store
+        .update_post(*id, update_post.title.clone(), update_post.content.clone())
+        .await?;
     Ok(HttpResponse::Ok().json(updated_post))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/posts/{id}",
+    params(("id" = Uuid, Path, description = "Post id")),
+    responses(
+        (status = 204, description = "Post deleted"),
+        (status = 401, description = "Missing or invalid bearer token", body = crate::errors::ErrorResponse),
+        (status = 404, description = "No post with that id", body = crate::errors::ErrorResponse),
+    ),
+    security(("bearer_auth" = [])),
+)]
 #[delete("/posts/{id}")]
-pub async fn delete_post(storage: web::Data<Storage>, id: web::Path<Uuid>) -> ApiResult<HttpResponse> {
-    storage.delete_post(*id)?;
+pub async fn delete_post(
+    store: web::Data<Arc<dyn BlogStore>>,
+    _user: AuthenticatedUser,
+    id: web::Path<Uuid>,
+) -> ApiResult<HttpResponse> {
+    store.delete_post(*id).await?;
     Ok(HttpResponse::NoContent().finish())
-}
\ No newline at end of file
+}
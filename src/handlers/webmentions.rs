@@ -0,0 +1,61 @@
+use crate::errors::{ApiError, ApiResult};
+use crate::storage::BlogStore;
+use crate::webmentions::{post_id_from_target, WebmentionJob, WebmentionQueue};
+use actix_web::{post, web, HttpResponse};
+use serde::Deserialize;
+use std::sync::Arc;
+use url::Url;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct WebmentionForm {
+    pub source: String,
+    pub target: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/posts/{post_id}/webmention",
+    params(("post_id" = Uuid, Path, description = "Post the webmention targets")),
+    request_body(content = WebmentionForm, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 202, description = "Accepted for background verification"),
+        (status = 400, description = "source/target missing, malformed, not a canonical URL for this post, or target doesn't map to an existing post", body = crate::errors::ErrorResponse),
+    ),
+)]
+#[post("/posts/{post_id}/webmention")]
+pub async fn receive_webmention(
+    store: web::Data<Arc<dyn BlogStore>>,
+    queue: web::Data<WebmentionQueue>,
+    post_id: web::Path<Uuid>,
+    form: web::Form<WebmentionForm>,
+) -> ApiResult<HttpResponse> {
+    let source = Url::parse(&form.source)
+        .map_err(|_| ApiError::ValidationError("source must be a valid URL".to_string()))?;
+    let target = Url::parse(&form.target)
+        .map_err(|_| ApiError::ValidationError("target must be a valid URL".to_string()))?;
+
+    let target_post_id = post_id_from_target(&target)
+        .ok_or_else(|| ApiError::ValidationError("target must be a canonical post URL".to_string()))?;
+    if target_post_id != *post_id {
+        return Err(ApiError::ValidationError(
+            "target does not reference this post".to_string(),
+        ));
+    }
+
+    // Verify the post actually exists before queueing verification work. The
+    // request spec calls for 400 here, not the 404 a missing post usually gets.
+    store
+        .get_post(*post_id)
+        .await
+        .map_err(|_| ApiError::ValidationError("target does not map to an existing post".to_string()))?;
+
+    queue.enqueue(WebmentionJob {
+        post_id: *post_id,
+        source,
+        target,
+    });
+
+    Ok(HttpResponse::Accepted().finish())
+}
@@ -1,36 +1,69 @@
+mod auth;
 mod errors;
+mod handlers;
+mod media;
 mod models;
+mod openapi;
 mod storage;
-mod handlers;
+mod webmentions;
 
 use actix_web::{middleware, web, App, HttpServer};
+use auth::AuthStore;
+use media::MediaStore;
+use openapi::ApiDoc;
 use std::path::PathBuf;
-use storage::Storage;
+use std::sync::Arc;
+use storage::BlogStore;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+/// Maximum accepted media upload size, in bytes (10 MiB).
+const MAX_MEDIA_SIZE_BYTES: u64 = 10 * 1024 * 1024;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     println!("Starting Blog API server...");
 
-    // Initialize storage
-    let storage = Storage::new(PathBuf::from("data/blog.json"))
-        .expect("Failed to initialize storage");
+    // Pick a storage backend via STORAGE_BACKEND (file|memory|postgres); defaults to file.
+    let store: Arc<dyn BlogStore> = match std::env::var("STORAGE_BACKEND").as_deref() {
+        Ok("memory") => Arc::new(storage::MemoryStore::new()),
+        Ok("postgres") => {
+            let database_url = std::env::var("DATABASE_URL")
+                .expect("DATABASE_URL must be set when STORAGE_BACKEND=postgres");
+            let store = storage::PostgresStore::connect(&database_url)
+                .await
+                .expect("Failed to connect to Postgres");
+            Arc::new(store)
+        }
+        _ => {
+            let store = storage::FileStore::new(PathBuf::from("data/blog.json"))
+                .expect("Failed to initialize storage");
+            Arc::new(store)
+        }
+    };
+
+    let auth_store =
+        AuthStore::new(PathBuf::from("data/users.json")).expect("Failed to initialize auth store");
+    let webmention_queue = webmentions::spawn_worker(store.clone());
+    let media_store: Arc<dyn MediaStore> =
+        Arc::new(media::FsMediaStore::new(PathBuf::from("data/media"), MAX_MEDIA_SIZE_BYTES)
+            .expect("Failed to initialize media store"));
 
     println!("Server running at http://localhost:8080");
-    println!("\nAvailable endpoints:");
-    println!("  GET    /posts");
-    println!("  GET    /posts/{{id}}");
-    println!("  POST   /posts");
-    println!("  PUT    /posts/{{id}}");
-    println!("  DELETE /posts/{{id}}");
-    println!("  GET    /posts/{{post_id}}/comments");
-    println!("  POST   /posts/{{post_id}}/comments");
-    println!("  DELETE /comments/{{id}}");
+    println!("API docs at http://localhost:8080/swagger-ui/");
     println!("\nPress Ctrl+C to stop");
-    
+
+    let openapi = ApiDoc::openapi();
+
     HttpServer::new(move || {
         App::new()
-            .app_data(web::Data::new(storage.clone()))
+            .app_data(web::Data::new(store.clone()))
+            .app_data(web::Data::new(auth_store.clone()))
+            .app_data(web::Data::new(webmention_queue.clone()))
+            .app_data(web::Data::new(media_store.clone()))
             .wrap(middleware::Logger::default())
+            .service(handlers::auth::login)
+            .service(handlers::auth::me)
             .service(handlers::posts::get_posts)
             .service(handlers::posts::get_post)
             .service(handlers::posts::create_post)
@@ -39,6 +72,11 @@ async fn main() -> std::io::Result<()> {
             .service(handlers::comments::get_comments)
             .service(handlers::comments::create_comment)
             .service(handlers::comments::delete_comment)
+            .service(handlers::webmentions::receive_webmention)
+            .service(handlers::media::upload_media)
+            .service(handlers::media::get_media)
+            .service(handlers::media::get_media_thumbnail)
+            .service(SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", openapi.clone()))
     })
     .bind(("127.0.0.1", 8080))?
     .run()
@@ -0,0 +1,271 @@
+use crate::errors::{ApiError, ApiResult};
+use crate::media::{ByteStream, MediaStore};
+use async_trait::async_trait;
+use bytes::BytesMut;
+use futures_util::TryStreamExt;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+
+/// How many leading bytes of an upload are kept around for MIME sniffing.
+const SNIFF_BYTES: usize = 8192;
+/// Longest edge of a generated thumbnail, in pixels.
+const THUMBNAIL_MAX_DIM: u32 = 256;
+
+/// Filesystem-backed `MediaStore`. Uploads are streamed straight to a temp
+/// file while being hashed; once fully written the temp file is renamed to
+/// its content-addressed path (`<base_dir>/<hash[0..2]>/<hash>`), so storing
+/// the same bytes twice is a no-op rename rather than a duplicate file.
+pub struct FsMediaStore {
+    base_dir: PathBuf,
+    max_size_bytes: u64,
+    allowed_mimes: HashSet<&'static str>,
+}
+
+impl FsMediaStore {
+    pub fn new(base_dir: PathBuf, max_size_bytes: u64) -> ApiResult<Self> {
+        std::fs::create_dir_all(&base_dir)
+            .map_err(|e| ApiError::StorageError(format!("Failed to create media directory: {}", e)))?;
+
+        let allowed_mimes = [
+            "image/png",
+            "image/jpeg",
+            "image/gif",
+            "image/webp",
+            "application/pdf",
+        ]
+        .into_iter()
+        .collect();
+
+        Ok(Self {
+            base_dir,
+            max_size_bytes,
+            allowed_mimes,
+        })
+    }
+
+    fn content_path(&self, id: &str) -> PathBuf {
+        self.base_dir.join(&id[0..2]).join(id)
+    }
+
+    fn thumbnail_path(&self, id: &str) -> PathBuf {
+        self.base_dir.join(&id[0..2]).join(format!("{}.thumb.jpg", id))
+    }
+}
+
+/// `write()` only ever hands out lowercase hex SHA-256 digests, so anything
+/// else (wrong length, non-hex, path separators smuggled in from the
+/// unauthenticated `GET /media/{id}` route) is rejected before it ever
+/// reaches a path join, which is the only thing standing between this and
+/// both a panic on short/multi-byte input and a path-traversal read.
+fn is_valid_media_id(id: &str) -> bool {
+    id.len() == 64 && id.bytes().all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase())
+}
+
+#[async_trait]
+impl MediaStore for FsMediaStore {
+    async fn write(&self, mut stream: ByteStream) -> ApiResult<String> {
+        let tmp_path = self.base_dir.join(format!(".upload-{}", uuid::Uuid::new_v4()));
+        let mut tmp_file = tokio::fs::File::create(&tmp_path)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to create temp upload file: {}", e)))?;
+
+        let mut hasher = Sha256::new();
+        let mut total: u64 = 0;
+        let mut sniff_buf = BytesMut::new();
+
+        while let Some(chunk) = stream
+            .try_next()
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed reading upload stream: {}", e)))?
+        {
+            total += chunk.len() as u64;
+            if total > self.max_size_bytes {
+                drop(tmp_file);
+                let _ = tokio::fs::remove_file(&tmp_path).await;
+                return Err(ApiError::ValidationError(format!(
+                    "Upload exceeds maximum size of {} bytes",
+                    self.max_size_bytes
+                )));
+            }
+
+            if sniff_buf.len() < SNIFF_BYTES {
+                let take = chunk.len().min(SNIFF_BYTES - sniff_buf.len());
+                sniff_buf.extend_from_slice(&chunk[..take]);
+            }
+            hasher.update(&chunk);
+            tmp_file
+                .write_all(&chunk)
+                .await
+                .map_err(|e| ApiError::StorageError(format!("Failed writing upload to disk: {}", e)))?;
+        }
+        tmp_file
+            .flush()
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed flushing upload to disk: {}", e)))?;
+        drop(tmp_file);
+
+        let mime = infer::get(&sniff_buf)
+            .map(|kind| kind.mime_type())
+            .unwrap_or("application/octet-stream");
+        if !self.allowed_mimes.contains(mime) {
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return Err(ApiError::ValidationError(format!(
+                "Unsupported media type: {}",
+                mime
+            )));
+        }
+
+        let id = format!("{:x}", hasher.finalize());
+        let dest_path = self.content_path(&id);
+        if let Some(parent) = dest_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| ApiError::StorageError(format!("Failed to create media directory: {}", e)))?;
+        }
+        tokio::fs::rename(&tmp_path, &dest_path)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to store upload: {}", e)))?;
+
+        if mime.starts_with("image/") {
+            spawn_thumbnail(dest_path, self.thumbnail_path(&id));
+        }
+
+        Ok(id)
+    }
+
+    async fn read(&self, id: &str) -> ApiResult<(String, ByteStream)> {
+        if !is_valid_media_id(id) {
+            return Err(ApiError::MediaNotFound);
+        }
+        let path = self.content_path(id);
+        let mut file = tokio::fs::File::open(&path)
+            .await
+            .map_err(|_| ApiError::MediaNotFound)?;
+
+        let mut sniff_buf = vec![0u8; SNIFF_BYTES];
+        let read = file.read(&mut sniff_buf).await.unwrap_or(0);
+        sniff_buf.truncate(read);
+        file.seek(std::io::SeekFrom::Start(0))
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to read media: {}", e)))?;
+
+        let mime = infer::get(&sniff_buf)
+            .map(|kind| kind.mime_type().to_string())
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        let stream: ByteStream = Box::pin(ReaderStream::new(file));
+        Ok((mime, stream))
+    }
+
+    async fn read_thumbnail(&self, id: &str) -> ApiResult<ByteStream> {
+        if !is_valid_media_id(id) {
+            return Err(ApiError::MediaNotFound);
+        }
+        let file = tokio::fs::File::open(self.thumbnail_path(id))
+            .await
+            .map_err(|_| ApiError::MediaNotFound)?;
+
+        Ok(Box::pin(ReaderStream::new(file)))
+    }
+}
+
+/// Decoding and resizing are CPU-bound, so thumbnail generation runs on a
+/// blocking thread and is fire-and-forget: a failed or slow thumbnail should
+/// never hold up the upload response, and `read_thumbnail` just reports
+/// `MediaNotFound` until the file shows up.
+fn spawn_thumbnail(source_path: PathBuf, thumb_path: PathBuf) {
+    tokio::task::spawn_blocking(move || {
+        if let Err(e) = generate_thumbnail(&source_path, &thumb_path) {
+            eprintln!("Failed to generate thumbnail for {:?}: {}", source_path, e);
+        }
+    });
+}
+
+fn generate_thumbnail(source_path: &Path, thumb_path: &Path) -> ApiResult<()> {
+    let image = image::open(source_path)
+        .map_err(|e| ApiError::StorageError(format!("Failed to decode image: {}", e)))?;
+    let thumbnail = image.thumbnail(THUMBNAIL_MAX_DIM, THUMBNAIL_MAX_DIM);
+    thumbnail
+        .save(thumb_path)
+        .map_err(|e| ApiError::StorageError(format!("Failed to save thumbnail: {}", e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use futures_util::stream;
+
+    fn temp_base_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("blocking_backend-media-test-{}-{}", name, uuid::Uuid::new_v4()))
+    }
+
+    fn byte_stream(chunks: Vec<&'static [u8]>) -> ByteStream {
+        Box::pin(stream::iter(chunks.into_iter().map(|c| Ok(Bytes::from_static(c)))))
+    }
+
+    #[test]
+    fn is_valid_media_id_accepts_a_lowercase_sha256_hex_digest() {
+        let digest = format!("{:x}", Sha256::digest(b"hello"));
+        assert!(is_valid_media_id(&digest));
+    }
+
+    #[test]
+    fn is_valid_media_id_rejects_short_ids_without_panicking() {
+        assert!(!is_valid_media_id("a"));
+        assert!(!is_valid_media_id(""));
+    }
+
+    #[test]
+    fn is_valid_media_id_rejects_non_ascii_ids_without_panicking() {
+        assert!(!is_valid_media_id("€"));
+    }
+
+    #[test]
+    fn is_valid_media_id_rejects_path_traversal_and_uppercase() {
+        assert!(!is_valid_media_id("../../etc/passwd"));
+        assert!(!is_valid_media_id(&"A".repeat(64)));
+    }
+
+    #[tokio::test]
+    async fn write_rejects_a_disallowed_mime_type() {
+        let store = FsMediaStore::new(temp_base_dir("disallowed-mime"), 1024).unwrap();
+        // Plain text sniffs to "text/plain" or unknown, neither of which is allowlisted.
+        let err = store
+            .write(byte_stream(vec![b"just some plain text, not an allowed type"]))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::ValidationError(_)));
+    }
+
+    #[tokio::test]
+    async fn write_rejects_an_upload_over_the_size_limit() {
+        let store = FsMediaStore::new(temp_base_dir("too-large"), 4).unwrap();
+        let err = store.write(byte_stream(vec![b"way more than four bytes"])).await.unwrap_err();
+        assert!(matches!(err, ApiError::ValidationError(_)));
+    }
+
+    #[tokio::test]
+    async fn write_then_read_round_trips_an_allowed_mime_type() {
+        let store = FsMediaStore::new(temp_base_dir("png-roundtrip"), 1024 * 1024).unwrap();
+        // Minimal PNG signature plus enough bytes for `infer` to sniff "image/png".
+        let png_header: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0];
+
+        let id = store.write(byte_stream(vec![png_header])).await.unwrap();
+        assert!(is_valid_media_id(&id));
+
+        let (mime, _stream) = store.read(&id).await.unwrap();
+        assert_eq!(mime, "image/png");
+    }
+
+    #[tokio::test]
+    async fn read_rejects_a_malformed_id_before_touching_disk() {
+        let store = FsMediaStore::new(temp_base_dir("malformed-read"), 1024).unwrap();
+        let err = store.read("../../etc/passwd").await.unwrap_err();
+        assert!(matches!(err, ApiError::MediaNotFound));
+    }
+}
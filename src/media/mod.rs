@@ -0,0 +1,37 @@
+mod fs;
+
+pub use fs::FsMediaStore;
+
+use crate::errors::ApiResult;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream::BoxStream;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Response body of `POST /media`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MediaUploadResponse {
+    pub id: String,
+    pub url: String,
+}
+
+/// A stream of raw body bytes, used so uploads and downloads can be
+/// forwarded to/from disk without ever buffering the whole file in memory.
+pub type ByteStream = BoxStream<'static, std::io::Result<Bytes>>;
+
+/// Storage abstraction for uploaded media (images, etc.) attached to posts.
+#[async_trait]
+pub trait MediaStore: Send + Sync {
+    /// Streams `stream` to storage and returns the content-addressed id it
+    /// was stored under.
+    async fn write(&self, stream: ByteStream) -> ApiResult<String>;
+
+    /// Streams a previously stored item back along with its sniffed MIME type.
+    async fn read(&self, id: &str) -> ApiResult<(String, ByteStream)>;
+
+    /// Streams the downscaled thumbnail generated for an image upload.
+    /// Returns `ApiError::MediaNotFound` for a non-image upload or one whose
+    /// thumbnail hasn't finished generating yet.
+    async fn read_thumbnail(&self, id: &str) -> ApiResult<ByteStream>;
+}
@@ -1,9 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use utoipa::ToSchema;
 use crate::errors::ApiResult;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct Post {
     pub id: Uuid,
     pub title: String,
@@ -11,22 +12,33 @@ pub struct Post {
     pub author: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Ids of media previously uploaded via `POST /media` and attached to this post.
+    #[serde(default)]
+    pub media_ids: Vec<String>,
+    /// Human-readable identifier derived from the title, minted by the
+    /// storage backend (it owns the slug index and collision-resistant
+    /// counter). Empty between `Post::new` and the backend assigning it, and
+    /// for stored posts that were saved without a slug (it defaults to empty
+    /// on load and is only minted again when the title changes).
+    #[serde(default)]
+    pub slug: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreatePost {
     pub title: String,
     pub content: String,
-    pub author: String,
+    #[serde(default)]
+    pub media_ids: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdatePost {
     pub title: Option<String>,
     pub content: Option<String>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)] 
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct Comment {
     pub id: Uuid,
     pub post_id: Uuid,
@@ -34,24 +46,38 @@ pub struct Comment {
     pub author: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set when this comment was created from a verified Webmention, holding
+    /// the source page's URL so re-processing the same source updates it in
+    /// place instead of creating a duplicate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webmention_source: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateComment {
     pub post_id: Uuid,
     pub content: String,
-    pub author: String,
+}
+
+/// Response body of `GET /posts/{id}`: a post alongside its comments.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PostWithComments {
+    pub post: Post,
+    pub comments: Vec<Comment>,
 }
 
 impl Post {
-    pub fn new(create_post: CreatePost) -> Self {
+    /// `author` comes from the authenticated user, never from the request body.
+    pub fn new(create_post: CreatePost, author: String) -> Self {
         Self {
             id: Uuid::new_v4(),
             title: create_post.title,
             content: create_post.content,
-            author: create_post.author,
+            author,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            media_ids: create_post.media_ids,
+            slug: String::new(),
         }
     }
     pub fn update(&mut self, update_post: UpdatePost) -> ApiResult<()> {
@@ -67,14 +93,28 @@ impl Post {
 }
 
 impl Comment {
-    pub fn new(create_comment: CreateComment) -> Self {   
+    /// `author` comes from the authenticated user, never from the request body.
+    pub fn new(create_comment: CreateComment, author: String) -> Self {
         Self {
             id: Uuid::new_v4(),
             post_id: create_comment.post_id,
             content: create_comment.content,
-            author: create_comment.author,
+            author,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            webmention_source: None,
+        }
+    }
+
+    pub fn from_webmention(post_id: Uuid, author: String, content: String, source: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            post_id,
+            content,
+            author,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            webmention_source: Some(source),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+use utoipa::{
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+
+/// Aggregates every handler and schema into the single document served at
+/// `GET /api-docs/openapi.json`. Adding a route means adding it to `paths`
+/// here and to the `.service(...)` list in `main.rs`; the two are kept in
+/// sync by hand since `utoipa` has no way to discover routes on its own.
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::handlers::auth::login,
+        crate::handlers::auth::me,
+        crate::handlers::posts::get_posts,
+        crate::handlers::posts::get_post,
+        crate::handlers::posts::create_post,
+        crate::handlers::posts::update_post,
+        crate::handlers::posts::delete_post,
+        crate::handlers::comments::get_comments,
+        crate::handlers::comments::create_comment,
+        crate::handlers::comments::delete_comment,
+        crate::handlers::webmentions::receive_webmention,
+        crate::handlers::media::upload_media,
+        crate::handlers::media::get_media,
+        crate::handlers::media::get_media_thumbnail,
+    ),
+    components(schemas(
+        crate::models::Post,
+        crate::models::CreatePost,
+        crate::models::UpdatePost,
+        crate::models::Comment,
+        crate::models::CreateComment,
+        crate::models::PostWithComments,
+        crate::auth::LoginRequest,
+        crate::auth::LoginResponse,
+        crate::auth::MeResponse,
+        crate::media::MediaUploadResponse,
+        crate::handlers::webmentions::WebmentionForm,
+        crate::errors::ErrorResponse,
+    )),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "posts", description = "Blog posts"),
+        (name = "comments", description = "Comments, including verified Webmentions"),
+        (name = "media", description = "Post attachments"),
+        (name = "auth", description = "Login and session inspection"),
+    ),
+)]
+pub struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi
+            .components
+            .as_mut()
+            .expect("components registered above via #[openapi(components(...))]");
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).bearer_format("JWT").build()),
+        );
+    }
+}
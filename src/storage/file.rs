@@ -0,0 +1,327 @@
+use crate::errors::{ApiError, ApiResult};
+use crate::models::{Comment, Post, UpdatePost};
+use crate::storage::{slug, BlogStore};
+use async_trait::async_trait;
+use crossbeam_channel::{bounded, Sender};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// How long the background writer waits after the first dirty signal before
+/// flushing, so a burst of mutations coalesces into a single rewrite.
+const FLUSH_DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlogData {
+    pub posts: HashMap<Uuid, Post>,
+    pub comments: Vec<Comment>,
+    /// Maps every slug a post has ever had (its current one plus any retained
+    /// aliases from past titles) to that post's id.
+    #[serde(default)]
+    pub slug_index: HashMap<String, Uuid>,
+    /// Monotonic counter fed into slug minting so two posts with the same
+    /// title never collide.
+    #[serde(default)]
+    pub next_slug_seq: u64,
+}
+
+impl BlogData {
+    pub fn new() -> Self {
+        Self {
+            posts: HashMap::new(),
+            comments: Vec::new(),
+            slug_index: HashMap::new(),
+            next_slug_seq: 0,
+        }
+    }
+}
+
+/// JSON persistence with a debounced background writer: mutations update
+/// `data` in memory and ping `dirty_tx`, while a dedicated worker thread
+/// coalesces bursts and flushes to disk at most once every `FLUSH_DEBOUNCE`,
+/// writing a temp file and atomically renaming it over `file_path` so a
+/// crash mid-write never leaves a torn file behind.
+#[derive(Clone)]
+pub struct FileStore {
+    data: Arc<Mutex<BlogData>>,
+    file_path: PathBuf,
+    dirty_tx: Sender<()>,
+}
+
+impl FileStore {
+    pub fn new(file_path: PathBuf) -> ApiResult<Self> {
+        // Create data directory if it doesn't exist
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| ApiError::StorageError(format!("Failed to create data directory: {}", e)))?;
+        }
+
+        // Load existing data or create new
+        let data = if file_path.exists() {
+            let contents = fs::read_to_string(&file_path)
+                .map_err(|e| ApiError::StorageError(format!("Failed to read file: {}", e)))?;
+            serde_json::from_str(&contents)
+                .map_err(|e| ApiError::StorageError(format!("Failed to parse JSON: {}", e)))?
+        } else {
+            BlogData::new()
+        };
+
+        let data = Arc::new(Mutex::new(data));
+        let (dirty_tx, dirty_rx) = bounded::<()>(1);
+
+        let worker_data = Arc::clone(&data);
+        let worker_path = file_path.clone();
+        std::thread::spawn(move || {
+            // Each iteration waits for the first dirty signal, then drains any
+            // further signals that land within the debounce window before
+            // writing once, so a burst of mutations costs a single rewrite.
+            while dirty_rx.recv().is_ok() {
+                let deadline = std::time::Instant::now() + FLUSH_DEBOUNCE;
+                while let Some(remaining) = deadline.checked_duration_since(std::time::Instant::now()) {
+                    if dirty_rx.recv_timeout(remaining).is_err() {
+                        break;
+                    }
+                }
+                if let Err(e) = Self::flush_to_disk(&worker_data, &worker_path) {
+                    eprintln!("Background flush failed: {}", e);
+                }
+            }
+        });
+
+        let store = FileStore {
+            data,
+            file_path,
+            dirty_tx,
+        };
+
+        // Save initial data immediately if the file doesn't exist yet.
+        if !store.file_path.exists() {
+            Self::flush_to_disk(&store.data, &store.file_path)?;
+        }
+
+        Ok(store)
+    }
+
+    /// Mark the in-memory data dirty so the background worker picks it up.
+    /// Never blocks: the channel is only used as a wake-up ping, so a full
+    /// buffer (a flush already pending) is not an error.
+    fn mark_dirty(&self) {
+        let _ = self.dirty_tx.try_send(());
+    }
+
+    fn flush_to_disk(data: &Arc<Mutex<BlogData>>, file_path: &PathBuf) -> ApiResult<()> {
+        let json = {
+            let data = data.lock().unwrap();
+            serde_json::to_string_pretty(&*data)
+                .map_err(|e| ApiError::StorageError(format!("Failed to serialize: {}", e)))?
+        };
+
+        let tmp_path = file_path.with_extension("json.tmp");
+        let mut tmp_file = fs::File::create(&tmp_path)
+            .map_err(|e| ApiError::StorageError(format!("Failed to create temp file: {}", e)))?;
+        tmp_file
+            .write_all(json.as_bytes())
+            .map_err(|e| ApiError::StorageError(format!("Failed to write temp file: {}", e)))?;
+        tmp_file
+            .sync_all()
+            .map_err(|e| ApiError::StorageError(format!("Failed to sync temp file: {}", e)))?;
+
+        fs::rename(&tmp_path, file_path)
+            .map_err(|e| ApiError::StorageError(format!("Failed to rename temp file: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+impl Drop for FileStore {
+    fn drop(&mut self) {
+        // Graceful-shutdown hook: force a final synchronous flush so a dirty
+        // signal still sitting in the channel is never lost on exit.
+        if let Err(e) = Self::flush_to_disk(&self.data, &self.file_path) {
+            eprintln!("Final flush on shutdown failed: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl BlogStore for FileStore {
+    async fn get_all_posts(&self) -> ApiResult<HashMap<Uuid, Post>> {
+        let data = self.data.lock().unwrap();
+        Ok(data.posts.clone())
+    }
+
+    async fn get_post(&self, id: Uuid) -> ApiResult<Post> {
+        let data = self.data.lock().unwrap();
+        data.posts.get(&id).cloned().ok_or(ApiError::PostNotFound)
+    }
+
+    async fn create_post(&self, mut post: Post) -> ApiResult<Post> {
+        let mut data = self.data.lock().unwrap();
+        let seq = data.next_slug_seq;
+        data.next_slug_seq += 1;
+        post.slug = slug::mint(&post.title, seq);
+        data.slug_index.insert(post.slug.clone(), post.id);
+        data.posts.insert(post.id, post.clone());
+        drop(data);
+        self.mark_dirty();
+        Ok(post)
+    }
+
+    async fn update_post(&self, id: Uuid, title: Option<String>, content: Option<String>) -> ApiResult<Post> {
+        let mut data = self.data.lock().unwrap();
+
+        let title_changed = {
+            let post = data.posts.get(&id).ok_or(ApiError::PostNotFound)?;
+            matches!(&title, Some(t) if t != &post.title)
+        };
+
+        {
+            let post = data.posts.get_mut(&id).ok_or(ApiError::PostNotFound)?;
+            post.update(UpdatePost { title, content })?;
+        }
+
+        if title_changed {
+            // Mint a fresh slug but keep the old one pointing at this post so
+            // existing links keep working.
+            let seq = data.next_slug_seq;
+            data.next_slug_seq += 1;
+            let new_slug = slug::mint(&data.posts[&id].title, seq);
+            data.slug_index.insert(new_slug.clone(), id);
+            data.posts.get_mut(&id).unwrap().slug = new_slug;
+        }
+
+        let updated_post = data.posts.get(&id).cloned().ok_or(ApiError::PostNotFound)?;
+        drop(data);
+        self.mark_dirty();
+        Ok(updated_post)
+    }
+
+    async fn delete_post(&self, id: Uuid) -> ApiResult<()> {
+        let mut data = self.data.lock().unwrap();
+        data.posts.remove(&id).ok_or(ApiError::PostNotFound)?;
+        // Remove all comments associated with the post
+        data.comments.retain(|c| c.post_id != id);
+        // Drop every slug (current and aliases) that pointed at this post.
+        data.slug_index.retain(|_, post_id| *post_id != id);
+        drop(data);
+        self.mark_dirty();
+        Ok(())
+    }
+
+    async fn resolve_post_id(&self, id_or_slug: &str) -> ApiResult<Uuid> {
+        let data = self.data.lock().unwrap();
+        if let Some(id) = data.slug_index.get(id_or_slug) {
+            return Ok(*id);
+        }
+        drop(data);
+        Uuid::parse_str(id_or_slug).map_err(|_| ApiError::PostNotFound)
+    }
+
+    async fn get_post_comments(&self, post_id: Uuid) -> ApiResult<Vec<Comment>> {
+        // Verify post exists
+        self.get_post(post_id).await?;
+
+        let data = self.data.lock().unwrap();
+        Ok(data
+            .comments
+            .iter()
+            .filter(|c| c.post_id == post_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create_comment(&self, comment: Comment) -> ApiResult<Comment> {
+        let mut data = self.data.lock().unwrap();
+        data.comments.push(comment.clone());
+        drop(data);
+        self.mark_dirty();
+        Ok(comment)
+    }
+
+    async fn delete_comment(&self, id: Uuid) -> ApiResult<()> {
+        let mut data = self.data.lock().unwrap();
+        let index = data
+            .comments
+            .iter()
+            .position(|c| c.id == id)
+            .ok_or(ApiError::CommentNotFound)?;
+
+        data.comments.remove(index);
+        drop(data);
+        self.mark_dirty();
+        Ok(())
+    }
+
+    async fn upsert_webmention_comment(
+        &self,
+        post_id: Uuid,
+        source: &str,
+        author: String,
+        content: String,
+    ) -> ApiResult<Comment> {
+        let mut data = self.data.lock().unwrap();
+        let existing = data
+            .comments
+            .iter_mut()
+            .find(|c| c.post_id == post_id && c.webmention_source.as_deref() == Some(source));
+
+        let comment = if let Some(existing) = existing {
+            existing.author = author;
+            existing.content = content;
+            existing.updated_at = chrono::Utc::now();
+            existing.clone()
+        } else {
+            let comment = Comment::from_webmention(post_id, author, content, source.to_string());
+            data.comments.push(comment.clone());
+            comment
+        };
+
+        drop(data);
+        self.mark_dirty();
+        Ok(comment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreatePost;
+
+    fn temp_store_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("blocking_backend-test-{}-{}.json", name, Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn a_burst_of_mutations_is_on_disk_with_every_write_after_the_debounce() {
+        let path = temp_store_path("debounce");
+        let store = FileStore::new(path.clone()).unwrap();
+
+        for i in 0..5 {
+            store
+                .create_post(Post::new(
+                    CreatePost {
+                        title: format!("Post {}", i),
+                        content: "content".to_string(),
+                        media_ids: Vec::new(),
+                    },
+                    "author".to_string(),
+                ))
+                .await
+                .unwrap();
+        }
+
+        // Give the debounced worker time to coalesce the burst and flush once.
+        tokio::time::sleep(FLUSH_DEBOUNCE + Duration::from_millis(200)).await;
+
+        let on_disk: BlogData = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(on_disk.posts.len(), 5);
+
+        drop(store);
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::errors::{ApiError, ApiResult};
+use crate::models::{Comment, Post, UpdatePost};
+use crate::storage::{slug, BlogStore};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Plain in-memory store with no persistence, useful for tests and local experimentation.
+#[derive(Default)]
+pub struct MemoryStore {
+    posts: Mutex<HashMap<Uuid, Post>>,
+    comments: Mutex<Vec<Comment>>,
+    slug_index: Mutex<HashMap<String, Uuid>>,
+    next_slug_seq: AtomicU64,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BlogStore for MemoryStore {
+    async fn get_all_posts(&self) -> ApiResult<HashMap<Uuid, Post>> {
+        Ok(self.posts.lock().unwrap().clone())
+    }
+
+    async fn get_post(&self, id: Uuid) -> ApiResult<Post> {
+        self.posts
+            .lock()
+            .unwrap()
+            .get(&id)
+            .cloned()
+            .ok_or(ApiError::PostNotFound)
+    }
+
+    async fn create_post(&self, mut post: Post) -> ApiResult<Post> {
+        let seq = self.next_slug_seq.fetch_add(1, Ordering::SeqCst);
+        post.slug = slug::mint(&post.title, seq);
+        self.slug_index.lock().unwrap().insert(post.slug.clone(), post.id);
+        self.posts.lock().unwrap().insert(post.id, post.clone());
+        Ok(post)
+    }
+
+    async fn update_post(&self, id: Uuid, title: Option<String>, content: Option<String>) -> ApiResult<Post> {
+        let mut posts = self.posts.lock().unwrap();
+        let post = posts.get_mut(&id).ok_or(ApiError::PostNotFound)?;
+        let title_changed = matches!(&title, Some(t) if t != &post.title);
+        post.update(UpdatePost { title, content })?;
+
+        if title_changed {
+            let seq = self.next_slug_seq.fetch_add(1, Ordering::SeqCst);
+            let new_slug = slug::mint(&post.title, seq);
+            self.slug_index.lock().unwrap().insert(new_slug.clone(), id);
+            post.slug = new_slug;
+        }
+
+        Ok(post.clone())
+    }
+
+    async fn delete_post(&self, id: Uuid) -> ApiResult<()> {
+        self.posts
+            .lock()
+            .unwrap()
+            .remove(&id)
+            .ok_or(ApiError::PostNotFound)?;
+        self.comments.lock().unwrap().retain(|c| c.post_id != id);
+        self.slug_index.lock().unwrap().retain(|_, post_id| *post_id != id);
+        Ok(())
+    }
+
+    async fn resolve_post_id(&self, id_or_slug: &str) -> ApiResult<Uuid> {
+        if let Some(id) = self.slug_index.lock().unwrap().get(id_or_slug) {
+            return Ok(*id);
+        }
+        Uuid::parse_str(id_or_slug).map_err(|_| ApiError::PostNotFound)
+    }
+
+    async fn get_post_comments(&self, post_id: Uuid) -> ApiResult<Vec<Comment>> {
+        self.get_post(post_id).await?;
+        Ok(self
+            .comments
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| c.post_id == post_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create_comment(&self, comment: Comment) -> ApiResult<Comment> {
+        self.comments.lock().unwrap().push(comment.clone());
+        Ok(comment)
+    }
+
+    async fn delete_comment(&self, id: Uuid) -> ApiResult<()> {
+        let mut comments = self.comments.lock().unwrap();
+        let index = comments
+            .iter()
+            .position(|c| c.id == id)
+            .ok_or(ApiError::CommentNotFound)?;
+        comments.remove(index);
+        Ok(())
+    }
+
+    async fn upsert_webmention_comment(
+        &self,
+        post_id: Uuid,
+        source: &str,
+        author: String,
+        content: String,
+    ) -> ApiResult<Comment> {
+        let mut comments = self.comments.lock().unwrap();
+        let existing = comments
+            .iter_mut()
+            .find(|c| c.post_id == post_id && c.webmention_source.as_deref() == Some(source));
+
+        let comment = if let Some(existing) = existing {
+            existing.author = author;
+            existing.content = content;
+            existing.updated_at = chrono::Utc::now();
+            existing.clone()
+        } else {
+            let comment = Comment::from_webmention(post_id, author, content, source.to_string());
+            comments.push(comment.clone());
+            comment
+        };
+
+        Ok(comment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateComment, CreatePost};
+
+    fn new_post(title: &str) -> Post {
+        Post::new(
+            CreatePost {
+                title: title.to_string(),
+                content: "content".to_string(),
+                media_ids: Vec::new(),
+            },
+            "author".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn create_then_get_post_round_trips() {
+        let store = MemoryStore::new();
+        let created = store.create_post(new_post("Hello World")).await.unwrap();
+
+        let fetched = store.get_post(created.id).await.unwrap();
+        assert_eq!(fetched.id, created.id);
+        assert_eq!(fetched.title, "Hello World");
+    }
+
+    #[tokio::test]
+    async fn get_post_on_unknown_id_is_not_found() {
+        let store = MemoryStore::new();
+        let err = store.get_post(Uuid::new_v4()).await.unwrap_err();
+        assert!(matches!(err, ApiError::PostNotFound));
+    }
+
+    #[tokio::test]
+    async fn resolve_post_id_accepts_uuid_or_slug() {
+        let store = MemoryStore::new();
+        let post = store.create_post(new_post("Hello World")).await.unwrap();
+
+        assert_eq!(
+            store.resolve_post_id(&post.id.to_string()).await.unwrap(),
+            post.id
+        );
+        assert_eq!(store.resolve_post_id(&post.slug).await.unwrap(), post.id);
+    }
+
+    #[tokio::test]
+    async fn update_post_title_mints_a_new_slug_and_keeps_the_old_as_an_alias() {
+        let store = MemoryStore::new();
+        let post = store.create_post(new_post("Original Title")).await.unwrap();
+        let old_slug = post.slug.clone();
+
+        let updated = store
+            .update_post(post.id, Some("New Title".to_string()), None)
+            .await
+            .unwrap();
+
+        assert_ne!(updated.slug, old_slug);
+        assert_eq!(store.resolve_post_id(&old_slug).await.unwrap(), post.id);
+        assert_eq!(store.resolve_post_id(&updated.slug).await.unwrap(), post.id);
+    }
+
+    #[tokio::test]
+    async fn update_post_resending_the_same_title_does_not_mint_a_new_slug() {
+        let store = MemoryStore::new();
+        let post = store.create_post(new_post("Original Title")).await.unwrap();
+
+        let updated = store
+            .update_post(post.id, Some("Original Title".to_string()), Some("new content".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(updated.slug, post.slug);
+        assert_eq!(updated.content, "new content");
+    }
+
+    #[tokio::test]
+    async fn delete_post_removes_its_slugs_and_comments() {
+        let store = MemoryStore::new();
+        let post = store.create_post(new_post("Gone Soon")).await.unwrap();
+        let slug = post.slug.clone();
+        store
+            .create_comment(Comment::new(
+                CreateComment {
+                    post_id: post.id,
+                    content: "nice post".to_string(),
+                },
+                "reader".to_string(),
+            ))
+            .await
+            .unwrap();
+
+        store.delete_post(post.id).await.unwrap();
+
+        assert!(matches!(
+            store.get_post(post.id).await.unwrap_err(),
+            ApiError::PostNotFound
+        ));
+        assert!(matches!(
+            store.resolve_post_id(&slug).await.unwrap_err(),
+            ApiError::PostNotFound
+        ));
+        assert!(store.get_post_comments(post.id).await.is_err());
+        assert!(store.comments.lock().unwrap().iter().all(|c| c.post_id != post.id));
+    }
+
+    #[tokio::test]
+    async fn upsert_webmention_comment_updates_in_place_instead_of_duplicating() {
+        let store = MemoryStore::new();
+        let post = store.create_post(new_post("A Post")).await.unwrap();
+
+        let first = store
+            .upsert_webmention_comment(
+                post.id,
+                "https://example.com/reply",
+                "example.com".to_string(),
+                "first pass".to_string(),
+            )
+            .await
+            .unwrap();
+        store
+            .upsert_webmention_comment(
+                post.id,
+                "https://example.com/reply",
+                "example.com".to_string(),
+                "updated pass".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let comments = store.get_post_comments(post.id).await.unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].id, first.id);
+        assert_eq!(comments[0].content, "updated pass");
+    }
+}
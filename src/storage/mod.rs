@@ -0,0 +1,46 @@
+mod file;
+mod memory;
+mod postgres;
+mod slug;
+
+pub use file::FileStore;
+pub use memory::MemoryStore;
+pub use postgres::PostgresStore;
+
+use crate::errors::ApiResult;
+use crate::models::{Comment, Post};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Persistence abstraction implemented by each storage backend (file, in-memory,
+/// Postgres, ...). `main.rs` picks one implementation at startup and hands the
+/// handlers a `Arc<dyn BlogStore>` so route code never depends on a concrete backend.
+#[async_trait]
+pub trait BlogStore: Send + Sync {
+    async fn get_all_posts(&self) -> ApiResult<HashMap<Uuid, Post>>;
+    async fn get_post(&self, id: Uuid) -> ApiResult<Post>;
+    async fn create_post(&self, post: Post) -> ApiResult<Post>;
+    async fn update_post(&self, id: Uuid, title: Option<String>, content: Option<String>) -> ApiResult<Post>;
+    async fn delete_post(&self, id: Uuid) -> ApiResult<()>;
+
+    /// Resolves a `GET /posts/{id}` path segment that may be either a post's
+    /// `Uuid` or one of its slugs (current or a retained alias from a past
+    /// title), trying the slug index first.
+    async fn resolve_post_id(&self, id_or_slug: &str) -> ApiResult<Uuid>;
+
+    async fn get_post_comments(&self, post_id: Uuid) -> ApiResult<Vec<Comment>>;
+    async fn create_comment(&self, comment: Comment) -> ApiResult<Comment>;
+    async fn delete_comment(&self, id: Uuid) -> ApiResult<()>;
+
+    /// Create or update the comment recorded for a verified Webmention from
+    /// `source` on `post_id`. Re-processing the same `post_id`/`source` pair
+    /// updates the existing comment in place rather than duplicating it.
+    async fn upsert_webmention_comment(
+        &self,
+        post_id: Uuid,
+        source: &str,
+        author: String,
+        content: String,
+    ) -> ApiResult<Comment>;
+}
@@ -0,0 +1,296 @@
+use crate::errors::{ApiError, ApiResult};
+use crate::models::{Comment, Post, UpdatePost};
+use crate::storage::{slug, BlogStore};
+use async_trait::async_trait;
+use sqlx::{postgres::PgPoolOptions, PgPool, Row};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Postgres-backed store. Expects a `posts` table
+/// (id uuid, title text, content text, author text, created_at timestamptz, updated_at timestamptz,
+/// media_ids text[], slug text),
+/// a `comments` table
+/// (id uuid, post_id uuid, content text, author text, created_at timestamptz, updated_at timestamptz,
+/// webmention_source text null),
+/// a `post_slugs` table (slug text primary key, post_id uuid) holding every slug a post has
+/// ever had -- its current one plus any retained aliases -- and a `post_slug_seq` sequence
+/// feeding the collision-resistant suffix minted into each new slug.
+#[derive(Clone)]
+pub struct PostgresStore {
+    pool: PgPool,
+}
+
+impl PostgresStore {
+    pub async fn connect(database_url: &str) -> ApiResult<Self> {
+        let pool = PgPoolOptions::new()
+            .max_connections(5)
+            .connect(database_url)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to connect to Postgres: {}", e)))?;
+
+        Ok(Self { pool })
+    }
+
+    fn row_to_post(row: &sqlx::postgres::PgRow) -> Post {
+        Post {
+            id: row.get("id"),
+            title: row.get("title"),
+            content: row.get("content"),
+            author: row.get("author"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            media_ids: row.get("media_ids"),
+            slug: row.get("slug"),
+        }
+    }
+
+    fn row_to_comment(row: &sqlx::postgres::PgRow) -> Comment {
+        Comment {
+            id: row.get("id"),
+            post_id: row.get("post_id"),
+            content: row.get("content"),
+            author: row.get("author"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            webmention_source: row.get("webmention_source"),
+        }
+    }
+}
+
+#[async_trait]
+impl BlogStore for PostgresStore {
+    async fn get_all_posts(&self) -> ApiResult<HashMap<Uuid, Post>> {
+        let rows = sqlx::query(
+            "SELECT id, title, content, author, created_at, updated_at, media_ids, slug FROM posts",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ApiError::StorageError(format!("Failed to load posts: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(Self::row_to_post)
+            .map(|post| (post.id, post))
+            .collect())
+    }
+
+    async fn get_post(&self, id: Uuid) -> ApiResult<Post> {
+        sqlx::query(
+            "SELECT id, title, content, author, created_at, updated_at, media_ids, slug FROM posts WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ApiError::StorageError(format!("Failed to load post: {}", e)))?
+        .map(|row| Self::row_to_post(&row))
+        .ok_or(ApiError::PostNotFound)
+    }
+
+    async fn create_post(&self, mut post: Post) -> ApiResult<Post> {
+        let seq: i64 = sqlx::query_scalar("SELECT nextval('post_slug_seq')")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to mint slug sequence: {}", e)))?;
+        post.slug = slug::mint(&post.title, seq as u64);
+
+        sqlx::query(
+            "INSERT INTO posts (id, title, content, author, created_at, updated_at, media_ids, slug) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(post.id)
+        .bind(&post.title)
+        .bind(&post.content)
+        .bind(&post.author)
+        .bind(post.created_at)
+        .bind(post.updated_at)
+        .bind(&post.media_ids)
+        .bind(&post.slug)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ApiError::StorageError(format!("Failed to insert post: {}", e)))?;
+
+        sqlx::query("INSERT INTO post_slugs (slug, post_id) VALUES ($1, $2)")
+            .bind(&post.slug)
+            .bind(post.id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to index post slug: {}", e)))?;
+
+        Ok(post)
+    }
+
+    async fn update_post(&self, id: Uuid, title: Option<String>, content: Option<String>) -> ApiResult<Post> {
+        let mut post = self.get_post(id).await?;
+        let title_changed = matches!(&title, Some(t) if t != &post.title);
+        post.update(UpdatePost { title, content })?;
+
+        if title_changed {
+            let seq: i64 = sqlx::query_scalar("SELECT nextval('post_slug_seq')")
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| ApiError::StorageError(format!("Failed to mint slug sequence: {}", e)))?;
+            post.slug = slug::mint(&post.title, seq as u64);
+
+            // Keep the old slug as a permanent alias by only adding the new one.
+            sqlx::query("INSERT INTO post_slugs (slug, post_id) VALUES ($1, $2)")
+                .bind(&post.slug)
+                .bind(id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| ApiError::StorageError(format!("Failed to index post slug: {}", e)))?;
+        }
+
+        sqlx::query("UPDATE posts SET title = $1, content = $2, updated_at = $3, slug = $4 WHERE id = $5")
+            .bind(&post.title)
+            .bind(&post.content)
+            .bind(post.updated_at)
+            .bind(&post.slug)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to update post: {}", e)))?;
+
+        Ok(post)
+    }
+
+    async fn delete_post(&self, id: Uuid) -> ApiResult<()> {
+        // Children first: `comments`/`post_slugs` reference `posts` by id, so
+        // deleting the parent row first would fail an FK constraint on any
+        // post with comments or slugs (every post has at least one slug row
+        // from `create_post`). Run the three deletes in
+        // a transaction so a crash mid-sequence can't orphan rows -- the
+        // mutex-backed FileStore/MemoryStore get that atomicity for free.
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to start transaction: {}", e)))?;
+
+        sqlx::query("DELETE FROM comments WHERE post_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to delete post comments: {}", e)))?;
+
+        sqlx::query("DELETE FROM post_slugs WHERE post_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to delete post slugs: {}", e)))?;
+
+        let result = sqlx::query("DELETE FROM posts WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to delete post: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApiError::PostNotFound);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn resolve_post_id(&self, id_or_slug: &str) -> ApiResult<Uuid> {
+        let slug_match: Option<Uuid> = sqlx::query_scalar("SELECT post_id FROM post_slugs WHERE slug = $1")
+            .bind(id_or_slug)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to resolve slug: {}", e)))?;
+
+        if let Some(id) = slug_match {
+            return Ok(id);
+        }
+
+        Uuid::parse_str(id_or_slug).map_err(|_| ApiError::PostNotFound)
+    }
+
+    async fn get_post_comments(&self, post_id: Uuid) -> ApiResult<Vec<Comment>> {
+        // Verify post exists
+        self.get_post(post_id).await?;
+
+        let rows = sqlx::query(
+            "SELECT id, post_id, content, author, created_at, updated_at, webmention_source FROM comments WHERE post_id = $1",
+        )
+        .bind(post_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ApiError::StorageError(format!("Failed to load comments: {}", e)))?;
+
+        Ok(rows.iter().map(Self::row_to_comment).collect())
+    }
+
+    async fn create_comment(&self, comment: Comment) -> ApiResult<Comment> {
+        sqlx::query(
+            "INSERT INTO comments (id, post_id, content, author, created_at, updated_at, webmention_source) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(comment.id)
+        .bind(comment.post_id)
+        .bind(&comment.content)
+        .bind(&comment.author)
+        .bind(comment.created_at)
+        .bind(comment.updated_at)
+        .bind(&comment.webmention_source)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ApiError::StorageError(format!("Failed to insert comment: {}", e)))?;
+
+        Ok(comment)
+    }
+
+    async fn delete_comment(&self, id: Uuid) -> ApiResult<()> {
+        let result = sqlx::query("DELETE FROM comments WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| ApiError::StorageError(format!("Failed to delete comment: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApiError::CommentNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn upsert_webmention_comment(
+        &self,
+        post_id: Uuid,
+        source: &str,
+        author: String,
+        content: String,
+    ) -> ApiResult<Comment> {
+        let existing = sqlx::query(
+            "SELECT id, post_id, content, author, created_at, updated_at, webmention_source FROM comments WHERE post_id = $1 AND webmention_source = $2",
+        )
+        .bind(post_id)
+        .bind(source)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ApiError::StorageError(format!("Failed to look up webmention comment: {}", e)))?
+        .map(|row| Self::row_to_comment(&row));
+
+        if let Some(mut comment) = existing {
+            comment.author = author;
+            comment.content = content;
+            comment.updated_at = chrono::Utc::now();
+
+            sqlx::query("UPDATE comments SET author = $1, content = $2, updated_at = $3 WHERE id = $4")
+                .bind(&comment.author)
+                .bind(&comment.content)
+                .bind(comment.updated_at)
+                .bind(comment.id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| ApiError::StorageError(format!("Failed to update webmention comment: {}", e)))?;
+
+            Ok(comment)
+        } else {
+            let comment = Comment::from_webmention(post_id, author, content, source.to_string());
+            self.create_comment(comment).await
+        }
+    }
+}
+
@@ -0,0 +1,71 @@
+use sqids::Sqids;
+
+/// Lowercases `title`, strips punctuation, and collapses whitespace/symbols
+/// into single hyphens, e.g. "Hello, World!" -> "hello-world".
+fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+    let mut last_was_hyphen = true; // suppresses a leading hyphen
+
+    for ch in title.to_lowercase().chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch);
+            last_was_hyphen = false;
+        } else if !last_was_hyphen {
+            slug.push('-');
+            last_was_hyphen = true;
+        }
+    }
+
+    let slug = slug.trim_end_matches('-').to_string();
+    if slug.is_empty() {
+        "post".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Mints a slug for `title` using `seq` (a monotonic per-store counter) to
+/// guarantee collision-resistance: two posts titled the same way get
+/// different slugs because they never share a `seq`.
+pub fn mint(title: &str, seq: u64) -> String {
+    format!("{}-{}", slugify(title), encode_seq(seq))
+}
+
+fn encode_seq(seq: u64) -> String {
+    Sqids::default()
+        .encode(&[seq])
+        .unwrap_or_else(|_| seq.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_lowercases_and_hyphenates() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+    }
+
+    #[test]
+    fn slugify_strips_leading_and_trailing_punctuation() {
+        assert_eq!(slugify("  --Readme.md--  "), "readme-md");
+    }
+
+    #[test]
+    fn slugify_falls_back_to_post_for_all_punctuation_titles() {
+        assert_eq!(slugify("!!!"), "post");
+        assert_eq!(slugify(""), "post");
+    }
+
+    #[test]
+    fn mint_appends_an_encoded_seq_suffix() {
+        let slug = mint("Hello World", 0);
+        assert!(slug.starts_with("hello-world-"));
+        assert!(slug.len() > "hello-world-".len());
+    }
+
+    #[test]
+    fn mint_differs_for_the_same_title_at_different_seqs() {
+        assert_ne!(mint("Same Title", 0), mint("Same Title", 1));
+    }
+}
@@ -0,0 +1,40 @@
+mod verify;
+
+pub use verify::spawn_worker;
+
+use url::Url;
+use uuid::Uuid;
+
+/// A verification job enqueued for the background worker: fetch `source`,
+/// confirm it links back to `target`, and only then record a comment.
+#[derive(Debug, Clone)]
+pub struct WebmentionJob {
+    pub post_id: Uuid,
+    pub source: Url,
+    pub target: Url,
+}
+
+/// Handle handed to route handlers for enqueueing verification jobs without
+/// blocking the request on the remote fetch.
+#[derive(Clone)]
+pub struct WebmentionQueue {
+    tx: tokio::sync::mpsc::UnboundedSender<WebmentionJob>,
+}
+
+impl WebmentionQueue {
+    pub fn enqueue(&self, job: WebmentionJob) {
+        // The worker outlives every sender, so this only fails if the
+        // process is already shutting down; nothing useful to do then.
+        let _ = self.tx.send(job);
+    }
+}
+
+/// Extracts the post id from a canonical post URL of the form
+/// `<base>/posts/{uuid}`, as produced by `GET /posts/{id}`.
+pub fn post_id_from_target(target: &Url) -> Option<Uuid> {
+    let segments: Vec<&str> = target.path_segments()?.collect();
+    match segments.as_slice() {
+        [.., "posts", id] => Uuid::parse_str(id).ok(),
+        _ => None,
+    }
+}
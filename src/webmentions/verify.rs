@@ -0,0 +1,96 @@
+use crate::storage::BlogStore;
+use crate::webmentions::{WebmentionJob, WebmentionQueue};
+use scraper::{Html, Selector};
+use std::sync::Arc;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+/// Spawns the off-request-path worker that verifies and records webmentions,
+/// mirroring the dedicated background worker used for storage persistence so
+/// a slow remote fetch never stalls a request thread.
+pub fn spawn_worker(store: Arc<dyn BlogStore>) -> WebmentionQueue {
+    let (tx, rx) = unbounded_channel();
+    tokio::spawn(run(store, rx));
+    WebmentionQueue { tx }
+}
+
+async fn run(store: Arc<dyn BlogStore>, mut rx: UnboundedReceiver<WebmentionJob>) {
+    while let Some(job) = rx.recv().await {
+        if let Err(e) = process(&store, job).await {
+            eprintln!("Webmention verification failed: {}", e);
+        }
+    }
+}
+
+async fn process(store: &Arc<dyn BlogStore>, job: WebmentionJob) -> Result<(), Box<dyn std::error::Error>> {
+    let body = reqwest::get(job.source.clone()).await?.text().await?;
+
+    if !links_back_to(&body, job.target.as_str()) {
+        // Silently drop: the source no longer links back to the target.
+        return Ok(());
+    }
+
+    let author = job.source.host_str().unwrap_or("unknown").to_string();
+    let content = extract_snippet(&body).unwrap_or_else(|| job.source.to_string());
+
+    store
+        .upsert_webmention_comment(job.post_id, job.source.as_str(), author, content)
+        .await?;
+
+    Ok(())
+}
+
+fn links_back_to(body: &str, target: &str) -> bool {
+    let document = Html::parse_document(body);
+    let selector = Selector::parse("a[href]").expect("static selector is valid");
+    document
+        .select(&selector)
+        .filter_map(|el| el.value().attr("href"))
+        .any(|href| href == target)
+}
+
+/// Pulls a short snippet (the first `<p>`, at most 200 characters) out of the
+/// source page to use as the comment body. Returns `None` when nothing usable
+/// is found; `process` then uses the bare source URL instead.
+fn extract_snippet(body: &str) -> Option<String> {
+    let document = Html::parse_document(body);
+    let selector = Selector::parse("p").ok()?;
+    let text: String = document.select(&selector).next()?.text().collect();
+    let snippet: String = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if snippet.is_empty() {
+        None
+    } else {
+        Some(snippet.chars().take(200).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_back_to_finds_a_matching_href() {
+        let body = r#"<html><body><a href="https://blog.example/posts/1">reply</a></body></html>"#;
+        assert!(links_back_to(body, "https://blog.example/posts/1"));
+    }
+
+    #[test]
+    fn links_back_to_is_false_when_no_href_matches() {
+        let body = r#"<html><body><a href="https://elsewhere.example/">nope</a></body></html>"#;
+        assert!(!links_back_to(body, "https://blog.example/posts/1"));
+    }
+
+    #[test]
+    fn extract_snippet_collapses_whitespace_and_truncates() {
+        let body = "<html><body><p>Hello\n   World</p></body></html>";
+        assert_eq!(extract_snippet(body), Some("Hello World".to_string()));
+
+        let long_body = format!("<html><body><p>{}</p></body></html>", "a".repeat(300));
+        assert_eq!(extract_snippet(&long_body), Some("a".repeat(200)));
+    }
+
+    #[test]
+    fn extract_snippet_is_none_without_any_paragraph_text() {
+        let body = "<html><body><div>no paragraphs here</div></body></html>";
+        assert_eq!(extract_snippet(body), None);
+    }
+}